@@ -0,0 +1,157 @@
+//! Proc-macro companion for `rust_autograd`.
+//!
+//! [`autodiff!`] parses an ordinary Rust math expression, lowers it onto the
+//! `Var` API, runs the forward/backward pass and returns the evaluated output
+//! together with the gradient w.r.t. the marked inputs. Numeric literals become
+//! non-`requires_grad` constant leaves; each identifier before `=>` is marked
+//! `requires_grad = true`. `+ - * /`, unary neg, and the elementwise / linear
+//! method calls (`.exp()`, `.ln()`, `.sin()`, `.cos()`, `.sigmoid()`,
+//! `.tanh()`, `.relu()`, `.powf(n)`, `.scale(n)`, `.dot(v)`) are supported.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, Expr, Ident, Token, UnOp};
+
+/// `inputs => body`, where `inputs` is a non-empty comma-separated list of the
+/// identifiers to differentiate with respect to.
+struct Autodiff {
+    inputs: Punctuated<Ident, Token![,]>,
+    body: Expr,
+}
+
+impl Parse for Autodiff {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut inputs = Punctuated::new();
+        loop {
+            inputs.push_value(input.parse::<Ident>()?);
+            if input.peek(Token![=>]) {
+                break;
+            }
+            inputs.push_punct(input.parse::<Token![,]>()?);
+        }
+        input.parse::<Token![=>]>()?;
+        let body = input.parse::<Expr>()?;
+        Ok(Autodiff { inputs, body })
+    }
+}
+
+/// Lower a math expression into code that evaluates to an owned `Var`. Every
+/// sub-expression is bound to a temporary so the combining methods can take it
+/// by reference, mirroring how the graph is written by hand.
+fn lower(expr: &Expr) -> syn::Result<TokenStream2> {
+    match expr {
+        Expr::Lit(lit) => Ok(quote! { ::rust_autograd::variable::Var::new(#lit) }),
+        Expr::Path(path) if path.path.get_ident().is_some() => {
+            let id = path.path.get_ident().unwrap();
+            Ok(quote! { #id.clone_node() })
+        }
+        Expr::Paren(paren) => lower(&paren.expr),
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => {
+            let inner = lower(&unary.expr)?;
+            Ok(quote! { { let __t = #inner; __t.scale(-1.0) } })
+        }
+        Expr::Binary(bin) => {
+            let left = lower(&bin.left)?;
+            let right = lower(&bin.right)?;
+            let method = match bin.op {
+                syn::BinOp::Add(_) => quote! { add },
+                syn::BinOp::Sub(_) => quote! { sub },
+                syn::BinOp::Mul(_) => quote! { mul },
+                syn::BinOp::Div(_) => quote! { div },
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        expr,
+                        "autodiff! only supports the + - * / binary operators",
+                    ))
+                }
+            };
+            Ok(quote! { { let __l = #left; let __r = #right; __l.#method(&__r) } })
+        }
+        Expr::MethodCall(call) => {
+            let recv = lower(&call.receiver)?;
+            let method = &call.method;
+            match method.to_string().as_str() {
+                "exp" | "ln" | "sin" | "cos" | "sigmoid" | "tanh" | "relu" => {
+                    Ok(quote! { { let __r = #recv; __r.#method() } })
+                }
+                // Scalar-parameterised ops: the argument is a plain `f64`.
+                "powf" | "scale" => {
+                    let arg = &call.args[0];
+                    Ok(quote! { { let __r = #recv; __r.#method(#arg) } })
+                }
+                // `dot`'s argument is itself an expression to lower.
+                "dot" => {
+                    let arg = lower(&call.args[0])?;
+                    Ok(quote! { { let __r = #recv; let __a = #arg; __r.dot(&__a) } })
+                }
+                other => Err(syn::Error::new_spanned(
+                    method,
+                    format!("autodiff! does not support the `{other}` method"),
+                )),
+            }
+        }
+        _ => Err(syn::Error::new_spanned(
+            expr,
+            "autodiff! only supports literals, identifiers, + - * /, unary neg and method calls",
+        )),
+    }
+}
+
+fn expand(input: Autodiff, with_value: bool) -> syn::Result<TokenStream2> {
+    let mark = input.inputs.iter().map(|id| {
+        quote! { #id.set_requires_grad(true); }
+    });
+    let body = lower(&input.body)?;
+
+    let grads = if input.inputs.len() == 1 {
+        let id = input.inputs.first().unwrap();
+        quote! { #id.grad() }
+    } else {
+        let each = input.inputs.iter().map(|id| quote! { #id.grad() });
+        quote! { ( #(#each),* ) }
+    };
+
+    let result = if with_value {
+        quote! { (__out, #grads) }
+    } else {
+        quote! { #grads }
+    };
+
+    Ok(quote! {
+        {
+            #(#mark)*
+            let mut __out = #body;
+            __out.backward();
+            #result
+        }
+    })
+}
+
+/// Differentiate a math expression, returning `(value, grad)` (or a tuple of
+/// gradients when several inputs are marked):
+///
+/// ```ignore
+/// let mut x = Var::new(0.0);
+/// let (y, dy) = autodiff!(x => 1.0 / (1.0 + (-x).exp()));
+/// ```
+#[proc_macro]
+pub fn autodiff(input: TokenStream) -> TokenStream {
+    let parsed = parse_macro_input!(input as Autodiff);
+    match expand(parsed, true) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Like [`autodiff!`] but returns only the gradient(s), discarding the value.
+#[proc_macro]
+pub fn grad(input: TokenStream) -> TokenStream {
+    let parsed = parse_macro_input!(input as Autodiff);
+    match expand(parsed, false) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
@@ -1,9 +1,9 @@
 use std::fmt::Debug;
 
-use crate::traits::{ArithmeticOps, Dot, HasGrad, Reduce, Shape};
-use crate::variable::Var;
+use crate::traits::{ArithmeticOps, Dot, Elementwise, HasGrad, Reduce, Shape};
+use crate::variable::{Var, VariableType};
 
-fn compute_broadcasted_gradients<'a, T>(data: &T, parent_grad: &T) -> T
+pub(crate) fn compute_broadcasted_gradients<T>(data: &T, parent_grad: &T) -> T
 where
     T: HasGrad<T> + ArithmeticOps + Dot<Output = T> + Reduce + Shape + Debug,
 {
@@ -11,7 +11,7 @@ where
     // Sum out added dims
     let ndims_added = parent_grad.ndim() - data.ndim();
     for _ in 0..ndims_added {
-        parent_grad = parent_grad.sum_axis(ndims_added);
+        parent_grad = parent_grad.sum_axis(0);
     }
 
     // Sum across broadcasted (but non-added dims)
@@ -24,7 +24,7 @@ where
     parent_grad
 }
 
-pub fn add_backward<'a, T>(parent: &Var<T>, parent_grad: T)
+pub fn add_backward<T>(parent: &Var<T>, parent_grad: T)
 where
     T: HasGrad<T> + ArithmeticOps + Shape + Dot<Output = T> + Reduce + Debug,
 {
@@ -50,31 +50,32 @@ where
     }
 }
 
-pub fn sub_backward<'a, T>(parent: &Var<T>, parent_grad: T)
+pub fn sub_backward<T>(parent: &Var<T>, parent_grad: T)
 where
-    T: HasGrad<T> + ArithmeticOps + Dot<Output = T> + Reduce + Debug,
+    T: HasGrad<T> + ArithmeticOps + Shape + Dot<Output = T> + Reduce + Debug,
 {
     let l_dep = &parent.deps()[0];
     let r_dep = &parent.deps()[1];
 
     if l_dep.requires_grad {
         let l_data = l_dep.data().unwrap();
+        let local = compute_broadcasted_gradients(&l_data, &parent_grad);
         let l_current_grad = l_dep.grad().unwrap_or(l_data.get_zero_grad());
-        let new_grad = l_current_grad + parent_grad.clone();
-        l_dep.set_grad(new_grad);
+        l_dep.set_grad(l_current_grad + local);
     }
 
     if r_dep.requires_grad {
         let r_data = r_dep.data().unwrap();
+        let local = compute_broadcasted_gradients(&r_data, &parent_grad);
         let r_current_grad = r_dep.grad().unwrap_or(r_data.get_zero_grad());
-        let new_grad = r_current_grad - parent_grad; // Note the subtraction here
+        let new_grad = r_current_grad - local; // Note the subtraction here
         r_dep.set_grad(new_grad);
     }
 }
 
-pub fn mul_backward<'a, T>(parent: &Var<T>, parent_grad: T)
+pub fn mul_backward<T>(parent: &Var<T>, parent_grad: T)
 where
-    T: HasGrad<T> + ArithmeticOps + Dot<Output = T> + Reduce + Debug,
+    T: HasGrad<T> + ArithmeticOps + Shape + Dot<Output = T> + Reduce + Debug,
 {
     let l_dep = &parent.deps[0];
     let r_dep = &parent.deps[1];
@@ -83,17 +84,145 @@ where
         let l_data = l_dep.data().unwrap();
         let r_data = r_dep.data().unwrap();
 
+        let local = compute_broadcasted_gradients(&l_data, &(parent_grad.clone() * r_data));
         let l_current_grad = l_dep.grad().unwrap_or(l_data.get_zero_grad());
-        let new_grad = l_current_grad + parent_grad.clone() * r_data;
-        l_dep.set_grad(new_grad);
+        l_dep.set_grad(l_current_grad + local);
     }
 
     if r_dep.requires_grad {
         let r_data = r_dep.data().unwrap();
         let l_data = l_dep.data().unwrap();
 
+        let local = compute_broadcasted_gradients(&r_data, &(parent_grad * l_data));
         let r_current_grad = r_dep.grad().unwrap_or(r_data.get_zero_grad());
-        let new_grad = r_current_grad + parent_grad * l_data;
-        r_dep.set_grad(new_grad);
+        r_dep.set_grad(r_current_grad + local);
+    }
+}
+
+pub fn div_backward<T>(parent: &Var<T>, parent_grad: T)
+where
+    T: HasGrad<T> + ArithmeticOps + Shape + Dot<Output = T> + Reduce + Debug,
+{
+    let l_dep = &parent.deps[0];
+    let r_dep = &parent.deps[1];
+
+    let l_data = l_dep.data().unwrap();
+    let r_data = r_dep.data().unwrap();
+
+    if l_dep.requires_grad {
+        // ∂(l / r)/∂l = 1 / r
+        let grad = parent_grad.clone() / r_data.clone();
+        let local = compute_broadcasted_gradients(&l_data, &grad);
+        let l_current_grad = l_dep.grad().unwrap_or(l_data.get_zero_grad());
+        l_dep.set_grad(l_current_grad + local);
+    }
+
+    if r_dep.requires_grad {
+        // ∂(l / r)/∂r = -l / r^2
+        let grad = -(parent_grad * l_data.clone() / (r_data.clone() * r_data.clone()));
+        let local = compute_broadcasted_gradients(&r_data, &grad);
+        let r_current_grad = r_dep.grad().unwrap_or(r_data.get_zero_grad());
+        r_dep.set_grad(r_current_grad + local);
+    }
+}
+
+/// Accumulate `local` into the single dependency's gradient, mirroring the
+/// `*_dep.grad().unwrap_or(zero) + contribution` pattern of the binary ops.
+fn accumulate_unary<T>(parent: &Var<T>, local: T)
+where
+    T: HasGrad<T> + ArithmeticOps + Elementwise + Debug,
+{
+    let dep = &parent.deps[0];
+    if dep.requires_grad {
+        let current_grad = dep.grad().unwrap_or(local.get_zero_grad());
+        dep.set_grad(current_grad + local);
     }
 }
+
+pub fn exp_backward<T>(parent: &Var<T>, parent_grad: T)
+where
+    T: HasGrad<T> + ArithmeticOps + Elementwise + Debug,
+{
+    let x = parent.deps[0].data().unwrap();
+    accumulate_unary(parent, parent_grad * x.exp());
+}
+
+pub fn ln_backward<T>(parent: &Var<T>, parent_grad: T)
+where
+    T: HasGrad<T> + ArithmeticOps + Elementwise + Debug,
+{
+    let x = parent.deps[0].data().unwrap();
+    accumulate_unary(parent, parent_grad / x);
+}
+
+pub fn sin_backward<T>(parent: &Var<T>, parent_grad: T)
+where
+    T: HasGrad<T> + ArithmeticOps + Elementwise + Debug,
+{
+    let x = parent.deps[0].data().unwrap();
+    // d/dx sin(x) = cos(x)
+    accumulate_unary(parent, parent_grad * x.cos());
+}
+
+pub fn cos_backward<T>(parent: &Var<T>, parent_grad: T)
+where
+    T: HasGrad<T> + ArithmeticOps + Elementwise + Debug,
+{
+    let x = parent.deps[0].data().unwrap();
+    // d/dx cos(x) = -sin(x)
+    accumulate_unary(parent, -(parent_grad * x.sin()));
+}
+
+pub fn sigmoid_backward<T>(parent: &Var<T>, parent_grad: T)
+where
+    T: HasGrad<T> + ArithmeticOps + Elementwise + Debug,
+{
+    let s = parent.deps[0].data().unwrap().sigmoid();
+    let ones = s.get_default_init_grad();
+    // s * (1 - s)
+    let local = s.clone() * (ones - s);
+    accumulate_unary(parent, parent_grad * local);
+}
+
+pub fn tanh_backward<T>(parent: &Var<T>, parent_grad: T)
+where
+    T: HasGrad<T> + ArithmeticOps + Elementwise + Debug,
+{
+    let t = parent.deps[0].data().unwrap().tanh();
+    let ones = t.get_default_init_grad();
+    // 1 - t^2
+    let local = ones - t.clone() * t;
+    accumulate_unary(parent, parent_grad * local);
+}
+
+pub fn relu_backward<T>(parent: &Var<T>, parent_grad: T)
+where
+    T: HasGrad<T> + ArithmeticOps + Elementwise + Debug,
+{
+    let mask = parent.deps[0].data().unwrap().relu_mask();
+    accumulate_unary(parent, parent_grad * mask);
+}
+
+pub fn powf_backward<T>(parent: &Var<T>, parent_grad: T)
+where
+    T: HasGrad<T> + ArithmeticOps + Elementwise + Debug,
+{
+    let VariableType::OpPow(n) = parent.var_type else {
+        unreachable!("powf_backward invoked on a non-pow node")
+    };
+    let x = parent.deps[0].data().unwrap();
+    // n * x^(n-1)
+    let local = x.powf(n - 1.0).scale(n);
+    accumulate_unary(parent, parent_grad * local);
+}
+
+pub fn scale_backward<T>(parent: &Var<T>, parent_grad: T)
+where
+    T: HasGrad<T> + ArithmeticOps + Elementwise + Debug,
+{
+    let VariableType::OpScale(n) = parent.var_type else {
+        unreachable!("scale_backward invoked on a non-scale node")
+    };
+    // d/dx (n * x) = n
+    accumulate_unary(parent, parent_grad.scale(n));
+}
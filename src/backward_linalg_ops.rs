@@ -1,27 +1,89 @@
-use crate::traits::{ArithmeticOps, Dot, HasGrad, Reduce, Transpose};
+use crate::traits::{ArithmeticOps, Dot, HasGrad, Reduce, Shape, Transpose};
 use crate::variable::Var;
 use std::fmt::Debug;
 
+/// Collapse any leading axes so a gradient matches its operand's rank. For
+/// most shapes the forward `dot` accepts the backward rules already yield a
+/// gradient at the operand's rank; the exception is a matrix operand paired
+/// with a size-1 batch (`[1, M, K] · [K, N]`), whose `[1, K, N]` gradient is
+/// summed back to `[K, N]` here. It also guards against accumulating a
+/// mismatched-rank gradient (which would silently broadcast-add). Unlike the
+/// elementwise reducer it never touches trailing size-1 axes, which for a
+/// matrix operand (e.g. a column vector `[n, 1]`) are real dimensions.
+fn reduce_batch_dims<T>(data: &T, grad: T) -> T
+where
+    T: Reduce + Shape,
+{
+    let mut grad = grad;
+    while grad.ndim() > data.ndim() {
+        grad = grad.sum_axis(0);
+    }
+    grad
+}
 
-pub fn dot_backward<'a, T>(parent: &Var<T>, parent_grad: T) 
+/// Backward for `dot`, valid for every rank that [`Dot`] accepts. The matrix
+/// and stacked-batch cases use the identities `grad_left = parent_grad · rhsᵀ`
+/// and `grad_right = lhsᵀ · parent_grad`, which extend batch-wise because
+/// `dot`/`t` operate on the last two axes per slice. The vector ranks need
+/// their own shapes — a matrix·vector product has a matrix gradient w.r.t. the
+/// matrix (an outer product), and a vector·vector dot has a vector gradient
+/// w.r.t. each operand — so they are handled explicitly rather than routed
+/// through the matrix formula (which would collapse to the wrong rank or hit
+/// the batch path and panic). The broadcast reducer then sums out any batch
+/// dims that were themselves broadcast.
+pub fn dot_backward<'a, T>(parent: &Var<T>, parent_grad: T)
 where
-    T: HasGrad<T> + ArithmeticOps + Dot<Output = T> +Transpose+ Reduce + Debug,
+    T: HasGrad<T> + ArithmeticOps + Dot<Output = T> + Transpose + Shape + Reduce + Debug,
 {
     let l_dep = &parent.deps[0];
     let r_dep = &parent.deps[1];
-    
+    let l_data = l_dep.data().unwrap();
+    let r_data = r_dep.data().unwrap();
+    let (ln, rn) = (l_data.ndim(), r_data.ndim());
+
     if l_dep.requires_grad {
-        let grad_wrt_left = parent_grad.dot(r_dep.data().unwrap().t());
-        let current_grad = l_dep.grad().unwrap_or(r_dep.data().unwrap().get_zero_grad());
-        let new_grad = current_grad + grad_wrt_left;
-        parent.deps[0].set_grad(new_grad);
+        let grad_wrt_left = match (ln, rn) {
+            // vector · vector -> scalar: ∂/∂a = g * b
+            (1, 1) => r_data.clone() * parent_grad.clone(),
+            // vector · matrix -> vector: ∂/∂a = B · g
+            (1, 2) => r_data.dot(parent_grad.clone()),
+            // matrix · vector -> vector: ∂/∂A = g ⊗ b
+            (2, 1) => parent_grad.outer(r_data.clone()),
+            // matrix / stacked batch: ∂/∂l = g · rᵀ
+            _ => parent_grad.clone().dot(r_data.t()),
+        };
+        let grad_wrt_left = reduce_batch_dims(&l_data, grad_wrt_left);
+        let current_grad = l_dep.grad().unwrap_or(l_data.get_zero_grad());
+        l_dep.set_grad(current_grad + grad_wrt_left);
     }
 
     if r_dep.requires_grad {
-        let grad_wrt_right = l_dep.data().unwrap().t().dot(parent_grad);
-        let current_grad = r_dep.grad().unwrap_or(r_dep.data().unwrap().get_zero_grad());
-        let new_grad = current_grad + grad_wrt_right;
-        parent.deps[1].set_grad(new_grad);
+        let grad_wrt_right = match (ln, rn) {
+            // vector · vector -> scalar: ∂/∂b = g * a
+            (1, 1) => l_data.clone() * parent_grad.clone(),
+            // vector · matrix -> vector: ∂/∂B = a ⊗ g
+            (1, 2) => l_data.outer(parent_grad.clone()),
+            // matrix · vector -> vector: ∂/∂b = Aᵀ · g
+            (2, 1) => l_data.t().dot(parent_grad.clone()),
+            // matrix / stacked batch: ∂/∂r = lᵀ · g
+            _ => l_data.t().dot(parent_grad.clone()),
+        };
+        let grad_wrt_right = reduce_batch_dims(&r_data, grad_wrt_right);
+        let current_grad = r_dep.grad().unwrap_or(r_data.get_zero_grad());
+        r_dep.set_grad(current_grad + grad_wrt_right);
     }
+}
 
+/// Backward for the last-two-axes transpose: the gradient flows back through
+/// another transpose of the same axes.
+pub fn transpose_backward<'a, T>(parent: &Var<T>, parent_grad: T)
+where
+    T: HasGrad<T> + ArithmeticOps + Transpose + Debug,
+{
+    let dep = &parent.deps[0];
+    if dep.requires_grad {
+        let local = parent_grad.t();
+        let current_grad = dep.grad().unwrap_or(dep.data().unwrap().get_zero_grad());
+        dep.set_grad(current_grad + local);
+    }
 }
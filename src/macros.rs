@@ -0,0 +1,24 @@
+//! Convenience macros for differentiating a plain math expression without
+//! hand-building every node.
+//!
+//! The macros are implemented as a proc-macro in the companion
+//! `autodiff_macros` crate (a proc-macro has to live in its own
+//! `proc-macro = true` crate) and re-exported here so callers can reach them as
+//! `rust_autograd::{autodiff, grad}`. The proc-macro parses an arbitrary Rust
+//! math expression such as `autodiff!(x => 1.0 / (1.0 + (-x).exp()))`, lowers
+//! `+ - * /`, unary neg, method calls (`.exp()`, `.ln()`, `.dot()`, …) and
+//! numeric literals onto the [`Var`] API — literals become non-`requires_grad`
+//! leaves, marked identifiers become `requires_grad = true` leaves — then runs
+//! `eval()`/`backward()` and hands back the value and the requested `grad()`s.
+//!
+//! ```ignore
+//! // y = sigmoid(x) = 1/(1+e^-x); at x = 0, y = 0.5 and dy = y(1-y) = 0.25
+//! let mut x = Var::new(0.0);
+//! let (y, dy) = autodiff!(x => 1.0 / (1.0 + (-x).exp()));
+//! assert_eq!(y.data().unwrap(), 0.5);
+//! assert_eq!(dy.unwrap(), 0.25);
+//! ```
+//!
+//! [`Var`]: crate::variable::Var
+
+pub use autodiff_macros::{autodiff, grad};
@@ -0,0 +1,161 @@
+use std::fmt::Debug;
+
+use crate::traits::{ArithmeticOps, HasGrad};
+
+/// Index of a node on the [`Tape`]. Every op returns one of these instead of
+/// cloning a subgraph, so sharing a node is just reusing its index.
+pub type NodeIdx = usize;
+
+/// A single parent edge: the already-computed local derivative `∂out/∂parent`
+/// at record time, together with the parent it points at.
+#[derive(Clone, Debug)]
+pub struct WeightedEdge<T> {
+    pub local_partial: T,
+    pub parent: NodeIdx,
+}
+
+impl<T> WeightedEdge<T> {
+    fn new(local_partial: T, parent: NodeIdx) -> Self {
+        WeightedEdge {
+            local_partial,
+            parent,
+        }
+    }
+}
+
+/// The parents of a node. Leaves have [`Parents::None`]; unary ops record a
+/// single edge and binary ops record two.
+#[derive(Clone, Debug)]
+pub enum Parents<T> {
+    None,
+    One(WeightedEdge<T>),
+    Two(WeightedEdge<T>, WeightedEdge<T>),
+}
+
+/// A recorded computation. `value` is the forward result; `parents` carries the
+/// local partials needed to pull gradients back to the inputs.
+#[derive(Clone, Debug)]
+pub struct Node<T> {
+    pub value: T,
+    pub parents: Parents<T>,
+}
+
+/// Flat Wengert-list autograd tape.
+///
+/// Nodes are appended in evaluation order, which is already a valid
+/// reverse-topological order: iterating indices in descending order during
+/// [`Tape::backward`] visits every (possibly shared) node exactly once, with no
+/// UUID hashing or `Rc<RefCell>` allocation.
+pub struct Tape<T> {
+    pub nodes: Vec<Node<T>>,
+    pub grads: Vec<T>,
+}
+
+impl<T: HasGrad<T> + ArithmeticOps + Debug> Default for Tape<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: HasGrad<T> + ArithmeticOps + Debug> Tape<T> {
+    pub fn new() -> Self {
+        Tape {
+            nodes: vec![],
+            grads: vec![],
+        }
+    }
+
+    fn push(&mut self, value: T, parents: Parents<T>) -> NodeIdx {
+        let idx = self.nodes.len();
+        self.nodes.push(Node { value, parents });
+        idx
+    }
+
+    /// Record a leaf (an input) and return its index.
+    pub fn leaf(&mut self, data: T) -> NodeIdx {
+        self.push(data, Parents::None)
+    }
+
+    /// Read back the value computed at `idx`.
+    pub fn value(&self, idx: NodeIdx) -> T {
+        self.nodes[idx].value.clone()
+    }
+
+    pub fn add(&mut self, l: NodeIdx, r: NodeIdx) -> NodeIdx {
+        let value = self.value(l) + self.value(r);
+        let ones = value.get_default_init_grad();
+        self.push(
+            value,
+            Parents::Two(
+                WeightedEdge::new(ones.clone(), l),
+                WeightedEdge::new(ones, r),
+            ),
+        )
+    }
+
+    pub fn sub(&mut self, l: NodeIdx, r: NodeIdx) -> NodeIdx {
+        let value = self.value(l) - self.value(r);
+        let ones = value.get_default_init_grad();
+        self.push(
+            value,
+            Parents::Two(
+                WeightedEdge::new(ones.clone(), l),
+                // ∂(l - r)/∂r = -1
+                WeightedEdge::new(-ones, r),
+            ),
+        )
+    }
+
+    pub fn mul(&mut self, l: NodeIdx, r: NodeIdx) -> NodeIdx {
+        let l_data = self.value(l);
+        let r_data = self.value(r);
+        let value = l_data.clone() * r_data.clone();
+        self.push(
+            value,
+            // ∂(l * r)/∂l = r, ∂(l * r)/∂r = l
+            Parents::Two(WeightedEdge::new(r_data, l), WeightedEdge::new(l_data, r)),
+        )
+    }
+
+    /// Run reverse-mode accumulation from `output_idx`. Seeds that node's
+    /// gradient with ones and sweeps the tape backwards, accumulating
+    /// `grads[parent] += local_partial * grads[node]` into every parent.
+    pub fn backward(&mut self, output_idx: NodeIdx) {
+        self.grads = self
+            .nodes
+            .iter()
+            .map(|n| n.value.get_zero_grad())
+            .collect();
+        self.grads[output_idx] = self.nodes[output_idx].value.get_default_init_grad();
+
+        for idx in (0..self.nodes.len()).rev() {
+            let node_grad = self.grads[idx].clone();
+            match &self.nodes[idx].parents {
+                Parents::None => (),
+                Parents::One(e) => {
+                    let contrib = e.local_partial.clone() * node_grad;
+                    let p = e.parent;
+                    self.grads[p] = self.grads[p].clone() + contrib;
+                }
+                Parents::Two(l, r) => {
+                    let l_contrib = l.local_partial.clone() * node_grad.clone();
+                    let r_contrib = r.local_partial.clone() * node_grad;
+                    let (lp, rp) = (l.parent, r.parent);
+                    self.grads[lp] = self.grads[lp].clone() + l_contrib;
+                    self.grads[rp] = self.grads[rp].clone() + r_contrib;
+                }
+            }
+        }
+    }
+
+    /// Gradient accumulated at `idx` by the last [`backward`](Tape::backward).
+    pub fn grad(&self, idx: NodeIdx) -> T {
+        self.grads[idx].clone()
+    }
+
+    /// Drop all recorded nodes and gradients so the tape can be reused.
+    pub fn reset_tape(&mut self) {
+        self.nodes.clear();
+        self.grads.clear();
+    }
+}
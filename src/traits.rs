@@ -21,6 +21,90 @@ pub trait HasGrad<T> {
     fn get_default_init_grad(&self) -> Self;
 }
 
+impl Elementwise for f64 {
+    fn exp(&self) -> Self {
+        f64::exp(*self)
+    }
+
+    fn ln(&self) -> Self {
+        f64::ln(*self)
+    }
+
+    fn sin(&self) -> Self {
+        f64::sin(*self)
+    }
+
+    fn cos(&self) -> Self {
+        f64::cos(*self)
+    }
+
+    fn sigmoid(&self) -> Self {
+        1.0 / (1.0 + (-self).exp())
+    }
+
+    fn tanh(&self) -> Self {
+        f64::tanh(*self)
+    }
+
+    fn relu(&self) -> Self {
+        self.max(0.0)
+    }
+
+    fn relu_mask(&self) -> Self {
+        if *self > 0.0 {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    fn powf(&self, n: f64) -> Self {
+        f64::powf(*self, n)
+    }
+
+    fn scale(&self, n: f64) -> Self {
+        self * n
+    }
+}
+
+// Scalars behave like rank-0 tensors: `dot`/`outer` are plain multiplication,
+// transpose and the reductions are the identity, and the shape is empty. These
+// let the `f64` engine satisfy the same linear-algebra trait surface as the
+// `NDArray` engine without special-casing it in the generic `Var` code.
+impl Dot for f64 {
+    type Output = f64;
+    fn dot(&self, other: Self) -> Self {
+        self * other
+    }
+    fn outer(&self, other: Self) -> Self {
+        self * other
+    }
+}
+
+impl Transpose for f64 {
+    fn t(&self) -> Self {
+        *self
+    }
+}
+
+impl Shape for f64 {
+    fn shape(&self) -> &[usize] {
+        &[]
+    }
+    fn ndim(&self) -> usize {
+        0
+    }
+}
+
+impl Reduce for f64 {
+    fn sum(&self) -> Self {
+        *self
+    }
+    fn sum_axis(&self, _axis: usize) -> Self {
+        *self
+    }
+}
+
 pub trait GetSetById<T> {
     fn get_by_id(&self, id: uuid::Uuid) -> Option<T>;
     fn set_by_id(&mut self, id: uuid::Uuid, val: T);
@@ -34,6 +118,26 @@ pub trait Reduce {
 pub trait Dot {
     type Output;
     fn dot(&self, other: Self) -> Self::Output;
+    /// Outer product of two rank-1 operands, `[M] ⊗ [N] -> [M, N]`. Used by the
+    /// `dot` backward rule for the vector·matrix and matrix·vector cases.
+    fn outer(&self, other: Self) -> Self::Output;
+}
+
+/// Elementwise nonlinear primitives used by the transcendental ops and their
+/// backward rules. `relu_mask` returns the `(x > 0)` indicator and `scale`
+/// multiplies by a scalar; both exist so backward passes can be written with
+/// the same trait surface as the forward ones.
+pub trait Elementwise {
+    fn exp(&self) -> Self;
+    fn ln(&self) -> Self;
+    fn sin(&self) -> Self;
+    fn cos(&self) -> Self;
+    fn sigmoid(&self) -> Self;
+    fn tanh(&self) -> Self;
+    fn relu(&self) -> Self;
+    fn relu_mask(&self) -> Self;
+    fn powf(&self, n: f64) -> Self;
+    fn scale(&self, n: f64) -> Self;
 }
 
 pub trait Transpose {
@@ -1,9 +1,9 @@
-use ndarray::{arr0, Array, Axis, CowArray, Dim, IxDynImpl};
+use ndarray::{arr0, s, Array, Array3, Axis, CowArray, Dim, Ix1, Ix2, IxDynImpl};
 use std::fmt::{Debug, Display};
 use std::ops::{Add, Div, Mul, Neg, Sub};
 use std::panic;
 
-use crate::traits::{Dot, HasGrad, Reduce, Transpose};
+use crate::traits::{Dot, Elementwise, HasGrad, Reduce, Shape, Transpose};
 
 #[derive(Clone, Debug)]
 pub struct NDArray<'a>(pub CowArray<'a, f64, Dim<IxDynImpl>>);
@@ -41,14 +41,26 @@ impl<'a> Sub for NDArray<'a> {
 impl<'a> Mul for NDArray<'a> {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self {
-        Self(self.0.mul(rhs.0.into_owned()).into())
+        let a = self.0.into_owned();
+        let b = rhs.0.into_owned();
+        // Broadcast so a scalar (or size-1 dim) can multiply a larger tensor,
+        // which the backward rules rely on (e.g. scalar gradient times vector).
+        if a.shape() == b.shape() {
+            Self((a * b).into())
+        } else if let Some(b) = b.broadcast(a.shape()) {
+            Self((&a * &b).into())
+        } else if let Some(a) = a.broadcast(b.shape()) {
+            Self((&a * &b).into())
+        } else {
+            Self((a * b).into())
+        }
     }
 }
 
 impl<'a> Div for NDArray<'a> {
     type Output = Self;
     fn div(self, rhs: Self) -> Self::Output {
-        Self(self.0.mul(rhs.0.into_owned()).into())
+        Self(self.0.div(rhs.0.into_owned()).into())
     }
 }
 
@@ -63,20 +75,131 @@ impl<'a> Neg for NDArray<'a> {
 impl<'a> Dot for NDArray<'a> {
     type Output = NDArray<'a>;
     fn dot(&self, other: Self) -> Self {
-        let lhs = if self.0.ndim() == 2 {
-            let shape = self.0.shape();
-            self.0.clone().into_shape((shape[0], shape[1])).unwrap()
-        } else {
-            panic!("dot() is only defined for rank-2 tensors")
+        let a = self.0.view();
+        let b = other.0.view();
+        let out = match (a.ndim(), b.ndim()) {
+            // vector · vector -> scalar
+            (1, 1) => {
+                let a1 = a.into_dimensionality::<Ix1>().unwrap();
+                let b1 = b.into_dimensionality::<Ix1>().unwrap();
+                arr0(a1.dot(&b1)).into_dyn()
+            }
+            // vector · matrix -> vector
+            (1, 2) => {
+                let a1 = a.into_dimensionality::<Ix1>().unwrap();
+                let b2 = b.into_dimensionality::<Ix2>().unwrap();
+                a1.dot(&b2).into_dyn()
+            }
+            // matrix · vector -> vector
+            (2, 1) => {
+                let a2 = a.into_dimensionality::<Ix2>().unwrap();
+                let b1 = b.into_dimensionality::<Ix1>().unwrap();
+                a2.dot(&b1).into_dyn()
+            }
+            // matrix · matrix -> matrix
+            (2, 2) => {
+                let a2 = a.into_dimensionality::<Ix2>().unwrap();
+                let b2 = b.into_dimensionality::<Ix2>().unwrap();
+                a2.dot(&b2).into_dyn()
+            }
+            // stacked batch dims: [..B, M, K] · [..B, K, N] -> [..B, M, N]
+            _ => {
+                let ash = a.shape().to_vec();
+                let bsh = b.shape().to_vec();
+                let (m, k) = (ash[ash.len() - 2], ash[ash.len() - 1]);
+                let n = bsh[bsh.len() - 1];
+                let batch: usize = ash[..ash.len() - 2].iter().product();
+
+                // Reshaping requires contiguous memory; a transposed operand
+                // (e.g. from `.t()` in a backward pass) is not, so normalise.
+                let a = a.as_standard_layout();
+                let b = b.as_standard_layout();
+                let a3 = a.into_shape((batch, m, k)).unwrap();
+                let b3 = b.into_shape((batch, k, n)).unwrap();
+                let mut out = Array3::<f64>::zeros((batch, m, n));
+                for i in 0..batch {
+                    let slice = a3.slice(s![i, .., ..]).dot(&b3.slice(s![i, .., ..]));
+                    out.slice_mut(s![i, .., ..]).assign(&slice);
+                }
+
+                let mut out_shape = ash[..ash.len() - 2].to_vec();
+                out_shape.push(m);
+                out_shape.push(n);
+                out.into_shape(out_shape).unwrap().into_dyn()
+            }
         };
+        Self(CowArray::from(out))
+    }
 
-        let rhs = if other.0.ndim() == 2 {
-            let shape = other.0.shape();
-            other.0.clone().into_shape((shape[0], shape[1])).unwrap()
-        } else {
-            panic!("dot() is only defined for rank-2 tensors")
-        };
-        Self(CowArray::from(lhs.dot(&rhs).into_dyn()))
+    /// Outer product of two rank-1 arrays: `[M] ⊗ [N] -> [M, N]`.
+    fn outer(&self, other: Self) -> Self {
+        let a = self
+            .0
+            .view()
+            .into_dimensionality::<Ix1>()
+            .unwrap()
+            .insert_axis(Axis(1));
+        let b = other
+            .0
+            .view()
+            .into_dimensionality::<Ix1>()
+            .unwrap()
+            .insert_axis(Axis(0));
+        Self(CowArray::from(a.dot(&b).into_dyn()))
+    }
+}
+
+impl<'a> Elementwise for NDArray<'a> {
+    fn exp(&self) -> Self {
+        Self(CowArray::from(self.0.mapv(f64::exp)))
+    }
+
+    fn ln(&self) -> Self {
+        Self(CowArray::from(self.0.mapv(f64::ln)))
+    }
+
+    fn sin(&self) -> Self {
+        Self(CowArray::from(self.0.mapv(f64::sin)))
+    }
+
+    fn cos(&self) -> Self {
+        Self(CowArray::from(self.0.mapv(f64::cos)))
+    }
+
+    fn sigmoid(&self) -> Self {
+        Self(CowArray::from(self.0.mapv(|x| 1.0 / (1.0 + (-x).exp()))))
+    }
+
+    fn tanh(&self) -> Self {
+        Self(CowArray::from(self.0.mapv(f64::tanh)))
+    }
+
+    fn relu(&self) -> Self {
+        Self(CowArray::from(self.0.mapv(|x| x.max(0.0))))
+    }
+
+    fn relu_mask(&self) -> Self {
+        Self(CowArray::from(
+            self.0.mapv(|x| if x > 0.0 { 1.0 } else { 0.0 }),
+        ))
+    }
+
+    fn powf(&self, n: f64) -> Self {
+        Self(CowArray::from(self.0.mapv(|x| x.powf(n))))
+    }
+
+    fn scale(&self, n: f64) -> Self {
+        Self(CowArray::from(self.0.mapv(|x| x * n)))
+    }
+}
+
+impl<'a> Shape for NDArray<'a> {
+    fn shape(&self) -> &[usize] {
+        self.0.shape()
+    }
+
+    fn ndim(&self) -> usize {
+        self.0.ndim()
     }
 }
 
@@ -92,15 +215,16 @@ impl<'a> Reduce for NDArray<'a> {
     }
 }
 
-impl<'a>  Transpose for NDArray<'a> {
+impl<'a> Transpose for NDArray<'a> {
+    /// Swap only the last two axes, leaving any leading batch axes in place.
+    /// A rank < 2 tensor is returned unchanged.
     fn t(&self) -> Self {
-        let transposed = if self.0.ndim() == 2 {
-            let tr = self.0.t();
-            let shape = tr.shape();
-            tr.clone().into_shape((shape[0], shape[1])).unwrap()
-        } else {
-            panic!("transpose() is only defined for rank-2 tensors")
-        };
-        Self(CowArray::from(transposed.into_owned().into_dyn()))
+        let nd = self.0.ndim();
+        if nd < 2 {
+            return self.clone();
+        }
+        let mut view = self.0.view();
+        view.swap_axes(nd - 2, nd - 1);
+        Self(CowArray::from(view.to_owned().into_dyn()))
     }
-}
\ No newline at end of file
+}
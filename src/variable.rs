@@ -1,5 +1,5 @@
 use std::cell::RefCell;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt::{Debug, Display};
 use std::rc::Rc;
 use std::vec;
@@ -7,16 +7,33 @@ use traits_ndarray::NDArray;
 
 use ndarray::{Array, CowArray, Dimension};
 
-use crate::backward_basic_ops::{add_backward, mul_backward, sub_backward};
-use crate::traits::{ArithmeticOps, HasGrad};
+use crate::backward_basic_ops::{
+    add_backward, cos_backward, div_backward, exp_backward, ln_backward, mul_backward,
+    powf_backward, relu_backward, scale_backward, sigmoid_backward, sin_backward, sub_backward,
+    tanh_backward,
+};
+use crate::backward_linalg_ops::{dot_backward, transpose_backward};
+use crate::traits::{ArithmeticOps, Dot, Elementwise, HasGrad, Reduce, Shape, Transpose};
 use crate::traits_ndarray;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
-enum VariableType {
+pub(crate) enum VariableType {
     Input,
     OpAdd,
     OpSub,
     OpMul,
+    OpDiv,
+    OpExp,
+    OpLn,
+    OpSin,
+    OpCos,
+    OpSigmoid,
+    OpTanh,
+    OpRelu,
+    OpPow(f64),
+    OpScale(f64),
+    OpDot,
+    OpT,
 }
 
 type BackwardFn<T> = fn(&Var<T>, T);
@@ -31,7 +48,7 @@ pub struct Var<T> {
     pub(crate) grad: Rc<RefCell<Option<T>>>,
     pub(crate) evaluated: bool,
     pub(crate) is_leaf: bool,
-    var_type: VariableType,
+    pub(crate) var_type: VariableType,
 }
 
 impl<T: Display + Clone> Debug for Var<T> {
@@ -56,7 +73,7 @@ pub fn from_ndarray<'a, D: Dimension>(data: Array<f64, D>) -> Var<NDArray<'a>> {
     Var::new(data)
 }
 
-impl<'a, T: HasGrad<T> + ArithmeticOps + Debug> Var<T> {
+impl<T: Clone> Var<T> {
     pub fn new(data: T) -> Self {
         Var {
             id: uuid::Uuid::new_v4(),
@@ -71,26 +88,84 @@ impl<'a, T: HasGrad<T> + ArithmeticOps + Debug> Var<T> {
         }
     }
 
-    fn dfs(
-        variable: &Var<T>,
-        visited: &mut HashSet<uuid::Uuid>,
-        stack: &mut Vec<Var<T>>,
-        allow_revisit: bool,
-    ) {
-        if allow_revisit || !visited.contains(&variable.id) {
-            visited.insert(variable.id);
-            for dep in &variable.deps {
-                Self::dfs(dep, visited, stack, allow_revisit);
+    /// Collect every distinct node reachable from `entry` together with each
+    /// node's `rank` (`1 + max(rank of deps)`, leaves are rank 0) and its
+    /// finish index. The walk is an explicit-stack DFS — no recursion,
+    /// so deep chains (e.g. a long `.add` chain) can't blow the stack — and each
+    /// shared node is recorded exactly once.
+    fn collect_ranked(entry: &Var<T>) -> Vec<(Var<T>, usize, usize)> {
+        let mut visited = HashSet::new();
+        // Finish order: a node is recorded only after all of its deps, even a
+        // dep shared with an earlier branch. Push deps in reverse so siblings
+        // pop left-to-right.
+        let mut stack = vec![(entry.copy(), false)];
+        let mut discovered: Vec<Var<T>> = Vec::new();
+        while let Some((node, expanded)) = stack.pop() {
+            if expanded {
+                discovered.push(node);
+                continue;
+            }
+            if !visited.insert(node.id) {
+                continue;
             }
-            stack.push(variable.copy());
+            let deps: Vec<Var<T>> = node.deps.iter().rev().map(|d| d.copy()).collect();
+            stack.push((node, true));
+            stack.extend(deps.into_iter().map(|d| (d, false)));
+        }
+
+        // Ranks: walk deps-before-node (finish order) and memoize.
+        let mut ranks: HashMap<uuid::Uuid, usize> = HashMap::new();
+        for node in discovered.iter() {
+            let rank = if node.is_leaf {
+                0
+            } else {
+                1 + node
+                    .deps
+                    .iter()
+                    .map(|d| *ranks.get(&d.id).unwrap_or(&0))
+                    .max()
+                    .unwrap_or(0)
+            };
+            ranks.insert(node.id, rank);
         }
+
+        discovered
+            .into_iter()
+            .enumerate()
+            .map(|(idx, node)| {
+                let rank = ranks[&node.id];
+                (node, rank, idx)
+            })
+            .collect()
     }
 
-    fn topological_sort(entry: &Var<T>, allow_revisit: bool) -> Vec<Var<T>> {
-        let mut visited = HashSet::new();
-        let mut stack = Vec::new();
-        Self::dfs(entry, &mut visited, &mut stack, allow_revisit);
-        stack.into_iter().collect()
+    /// Forward (evaluation) order: nodes by ascending rank so every dep is
+    /// visited before the op that consumes it. Ties are broken by finish
+    /// order purely for determinism. The `allow_revisit` flag is retained for
+    /// source compatibility but no longer re-expands shared subgraphs.
+    fn topological_sort(entry: &Var<T>, _allow_revisit: bool) -> Vec<Var<T>> {
+        let mut ranked = Self::collect_ranked(entry);
+        ranked.sort_by_key(|(_, rank, idx)| (*rank, *idx));
+        ranked.into_iter().map(|(node, _, _)| node).collect()
+    }
+
+    /// Backward order: drain a max-heap keyed on rank so a node is only emitted
+    /// after all of its consumers (which have strictly higher rank) have already
+    /// been emitted. Each node appears exactly once, so a shared node's
+    /// gradient is accumulated from every consumer but its `backward_fn` fires
+    /// a single time.
+    fn backward_order(entry: &Var<T>) -> Vec<Var<T>> {
+        let ranked = Self::collect_ranked(entry);
+        let mut heap: std::collections::BinaryHeap<(usize, usize, usize)> =
+            std::collections::BinaryHeap::new();
+        for (i, (_, rank, idx)) in ranked.iter().enumerate() {
+            heap.push((*rank, *idx, i));
+        }
+        let mut order = Vec::with_capacity(ranked.len());
+        while let Some((_, _, i)) = heap.pop() {
+            order.push(ranked[i].0.copy());
+        }
+        order
     }
 
     /// Make a cheap copy of self. Copying will create a new structure, but
@@ -109,32 +184,65 @@ impl<'a, T: HasGrad<T> + ArithmeticOps + Debug> Var<T> {
         }
     }
 
-    pub fn backward(&mut self) {
+    /// Mark every node that transitively depends on a `requires_grad` leaf.
+    /// A leaf's flag is its own `requires_grad`; an op node's flag is the OR of
+    /// its dependencies'. Only marked nodes take part in backprop.
+    fn compute_has_gradient(forward_sorted: &[Var<T>]) -> HashMap<uuid::Uuid, bool> {
+        let mut has_gradient = HashMap::new();
+        for var in forward_sorted {
+            let flag = if var.is_leaf {
+                var.requires_grad
+            } else {
+                var.deps
+                    .iter()
+                    .any(|d| *has_gradient.get(&d.id).unwrap_or(&false))
+            };
+            has_gradient.insert(var.id, flag);
+        }
+        has_gradient
+    }
+
+    pub fn backward(&mut self)
+    where
+        T: ArithmeticOps
+            + HasGrad<T>
+            + Elementwise
+            + Dot<Output = T>
+            + Transpose
+            + Shape
+            + Reduce
+            + Debug,
+    {
         self.eval();
 
-        // backward requires reverse-topological sort, allowing revisiting
-        let mut sorted = Self::topological_sort(self, true);
-        sorted.reverse();
+        // Mark the nodes that actually lead to a trainable leaf so dead
+        // branches are skipped during the reverse pass.
+        let has_gradient = Self::compute_has_gradient(&Self::topological_sort(self, false));
+
+        // Backward drains the rank-ordered max-heap: a node is processed only
+        // after all of its consumers have contributed their gradient, and each
+        // shared node is visited exactly once (no revisiting, no recursion).
+        let sorted = Self::backward_order(self);
 
         // run backward propagation in iterative manner
-        for i in 0..sorted.len() {
-            let var = &mut sorted[i];
-            match var.backward_fn {
-                Some(bw_fn) => {
-                    // var requires grad. Proceed.
-                    let var_val = var.data.borrow().clone().unwrap();
-
-                    // The grad of root node is set from get_default_init_grad(), which is
-                    // usually ones. Otherwise, get the grad from the grad_map by that node's id
-                    let grad = if var.id == self.id {
-                        var_val.get_default_init_grad()
-                    } else {
-                        var.grad.borrow().clone().unwrap()
-                    };
-
-                    bw_fn(var, grad);
-                }
-                None => (),
+        for var in &sorted {
+            if !has_gradient.get(&var.id).unwrap_or(&false) {
+                // No path from this node reaches a gradient-requiring leaf.
+                continue;
+            }
+            if let Some(bw_fn) = var.backward_fn {
+                // var requires grad. Proceed.
+                let var_val = var.data.borrow().clone().unwrap();
+
+                // The grad of root node is set from get_default_init_grad(), which is
+                // usually ones. Otherwise, take the grad its consumers accumulated in its grad cell
+                let grad = if var.id == self.id {
+                    var_val.get_default_init_grad()
+                } else {
+                    var.grad.borrow().clone().unwrap()
+                };
+
+                bw_fn(var, grad);
             }
         }
     }
@@ -160,13 +268,42 @@ impl<'a, T: HasGrad<T> + ArithmeticOps + Debug> Var<T> {
             grad: Rc::new(RefCell::new(None)),
             is_leaf: false,
             requires_grad: self.requires_grad || other.requires_grad,
-            var_type: var_type,
+            var_type,
             backward_fn: Some(backward_fn),
         }
     }
 
+    fn handle_unary_op(&self, var_type: VariableType, backward_fn: BackwardFn<T>) -> Var<T> {
+        Var {
+            id: uuid::Uuid::new_v4(),
+            data: Rc::new(RefCell::new(None)),
+            deps: vec![Box::new(self.copy())],
+            evaluated: false,
+            grad: Rc::new(RefCell::new(None)),
+            is_leaf: false,
+            requires_grad: self.requires_grad,
+            var_type,
+            backward_fn: Some(backward_fn),
+        }
+    }
+
+    pub fn eval_unary_op(parent: &Var<T>, op: impl Fn(T) -> T) {
+        let data = parent.deps[0].data.borrow().clone().unwrap();
+        parent.set_data(op(data));
+    }
+
     /// Evaluate computation graph and populate the data of intermediary variables
-    pub fn eval(&mut self) {
+    pub fn eval(&mut self)
+    where
+        T: ArithmeticOps
+            + HasGrad<T>
+            + Elementwise
+            + Dot<Output = T>
+            + Transpose
+            + Shape
+            + Reduce
+            + Debug,
+    {
         let sorted = Self::topological_sort(self, false);
 
         for var in sorted {
@@ -175,6 +312,18 @@ impl<'a, T: HasGrad<T> + ArithmeticOps + Debug> Var<T> {
                 VariableType::OpAdd => Self::eval_bin_op(&var, |a, b| a + b),
                 VariableType::OpSub => Self::eval_bin_op(&var, |a, b| a - b),
                 VariableType::OpMul => Self::eval_bin_op(&var, |a, b| a * b),
+                VariableType::OpDiv => Self::eval_bin_op(&var, |a, b| a / b),
+                VariableType::OpExp => Self::eval_unary_op(&var, |a| a.exp()),
+                VariableType::OpLn => Self::eval_unary_op(&var, |a| a.ln()),
+                VariableType::OpSin => Self::eval_unary_op(&var, |a| a.sin()),
+                VariableType::OpCos => Self::eval_unary_op(&var, |a| a.cos()),
+                VariableType::OpSigmoid => Self::eval_unary_op(&var, |a| a.sigmoid()),
+                VariableType::OpTanh => Self::eval_unary_op(&var, |a| a.tanh()),
+                VariableType::OpRelu => Self::eval_unary_op(&var, |a| a.relu()),
+                VariableType::OpPow(n) => Self::eval_unary_op(&var, |a| a.powf(n)),
+                VariableType::OpScale(n) => Self::eval_unary_op(&var, |a| a.scale(n)),
+                VariableType::OpDot => Self::eval_bin_op(&var, |a, b| a.dot(b)),
+                VariableType::OpT => Self::eval_unary_op(&var, |a| a.t()),
             }
         }
 
@@ -215,10 +364,177 @@ impl<'a, T: HasGrad<T> + ArithmeticOps + Debug> Var<T> {
     pub fn deps(&self) -> &Deps<T> {
         &self.deps
     }
+
+    pub fn id(&self) -> uuid::Uuid {
+        self.id
+    }
+
+    /// A cheap clone that shares this node's data and gradient cells. The
+    /// [`autodiff!`](crate::autodiff) macro uses it to reference a marked leaf
+    /// in more than one place while keeping gradients accumulating onto the
+    /// same underlying node.
+    pub fn clone_node(&self) -> Var<T> {
+        self.copy()
+    }
+
+    /// Accumulate a symbolic gradient contribution onto `dep`'s adjoint,
+    /// summing with any contribution already recorded from another consumer.
+    fn accumulate_adjoint(
+        adjoints: &mut HashMap<uuid::Uuid, Var<T>>,
+        dep: &Var<T>,
+        contrib: Var<T>,
+    ) where
+        T: ArithmeticOps
+            + HasGrad<T>
+            + Elementwise
+            + Dot<Output = T>
+            + Transpose
+            + Shape
+            + Reduce
+            + Debug,
+    {
+        let entry = match adjoints.remove(&dep.id) {
+            Some(existing) => existing.add(&contrib),
+            None => contrib,
+        };
+        adjoints.insert(dep.id, entry);
+    }
+
+    /// Build a *differentiable* gradient graph for `self`.
+    ///
+    /// Unlike [`backward`](Var::backward), which writes concrete numbers into
+    /// each leaf's `grad`, this returns a fresh symbolic `Var` per node id
+    /// representing `d(self)/d(node)`. The adjoints are assembled from the
+    /// ordinary `.add`/`.mul`/unary builders, so each returned `Var` is itself
+    /// a node in the autograd graph: evaluate it for the first derivative, or
+    /// call `grad_graph` on it again for a second derivative (e.g. a Hessian
+    /// diagonal).
+    pub fn grad_graph(&mut self) -> HashMap<uuid::Uuid, Var<T>>
+    where
+        T: ArithmeticOps
+            + HasGrad<T>
+            + Elementwise
+            + Dot<Output = T>
+            + Transpose
+            + Shape
+            + Reduce
+            + Debug,
+    {
+        self.eval();
+
+        let sorted = Self::topological_sort(self, false);
+        let mut adjoints: HashMap<uuid::Uuid, Var<T>> = HashMap::new();
+
+        // Seed the output with a symbolic "ones" constant of the right shape.
+        let seed = Var::new(self.data().unwrap().get_default_init_grad());
+        adjoints.insert(self.id, seed);
+
+        for node in sorted.iter().rev() {
+            let g = match adjoints.get(&node.id) {
+                Some(g) => g.copy(),
+                None => continue,
+            };
+            match node.var_type {
+                VariableType::Input => {}
+                VariableType::OpAdd => {
+                    Self::accumulate_adjoint(&mut adjoints, &node.deps[0], g.copy());
+                    Self::accumulate_adjoint(&mut adjoints, &node.deps[1], g);
+                }
+                VariableType::OpSub => {
+                    Self::accumulate_adjoint(&mut adjoints, &node.deps[0], g.copy());
+                    Self::accumulate_adjoint(&mut adjoints, &node.deps[1], g.scale(-1.0));
+                }
+                VariableType::OpMul => {
+                    let c0 = g.mul(&node.deps[1]);
+                    let c1 = g.mul(&node.deps[0]);
+                    Self::accumulate_adjoint(&mut adjoints, &node.deps[0], c0);
+                    Self::accumulate_adjoint(&mut adjoints, &node.deps[1], c1);
+                }
+                VariableType::OpDiv => {
+                    // ∂(l/r)/∂l = g/r, ∂(l/r)/∂r = -g*l/r^2
+                    let c0 = g.div(&node.deps[1]);
+                    let rr = node.deps[1].mul(&node.deps[1]);
+                    let c1 = g.mul(&node.deps[0]).div(&rr).scale(-1.0);
+                    Self::accumulate_adjoint(&mut adjoints, &node.deps[0], c0);
+                    Self::accumulate_adjoint(&mut adjoints, &node.deps[1], c1);
+                }
+                VariableType::OpExp => {
+                    let c = g.mul(&node.deps[0].exp());
+                    Self::accumulate_adjoint(&mut adjoints, &node.deps[0], c);
+                }
+                VariableType::OpLn => {
+                    let c = g.div(&node.deps[0]);
+                    Self::accumulate_adjoint(&mut adjoints, &node.deps[0], c);
+                }
+                VariableType::OpSin => {
+                    let c = g.mul(&node.deps[0].cos());
+                    Self::accumulate_adjoint(&mut adjoints, &node.deps[0], c);
+                }
+                VariableType::OpCos => {
+                    let c = g.mul(&node.deps[0].sin()).scale(-1.0);
+                    Self::accumulate_adjoint(&mut adjoints, &node.deps[0], c);
+                }
+                VariableType::OpSigmoid => {
+                    // s(1 - s), with s and the constant one carried as nodes
+                    let s = node.deps[0].sigmoid();
+                    let one = Var::new(node.deps[0].data().unwrap().get_default_init_grad());
+                    let c = g.mul(&s.mul(&one.sub(&s)));
+                    Self::accumulate_adjoint(&mut adjoints, &node.deps[0], c);
+                }
+                VariableType::OpTanh => {
+                    // 1 - t^2
+                    let t = node.deps[0].tanh();
+                    let one = Var::new(node.deps[0].data().unwrap().get_default_init_grad());
+                    let c = g.mul(&one.sub(&t.mul(&t)));
+                    Self::accumulate_adjoint(&mut adjoints, &node.deps[0], c);
+                }
+                VariableType::OpRelu => {
+                    // relu'(x) is the constant (x > 0) mask
+                    let mask = Var::new(node.deps[0].data().unwrap().relu_mask());
+                    let c = g.mul(&mask);
+                    Self::accumulate_adjoint(&mut adjoints, &node.deps[0], c);
+                }
+                VariableType::OpPow(n) => {
+                    let c = g.mul(&node.deps[0].powf(n - 1.0).scale(n));
+                    Self::accumulate_adjoint(&mut adjoints, &node.deps[0], c);
+                }
+                VariableType::OpScale(n) => {
+                    Self::accumulate_adjoint(&mut adjoints, &node.deps[0], g.scale(n));
+                }
+                VariableType::OpDot => {
+                    // Symbolic matrix adjoints ∂(l·r)/∂l = g · rᵀ and
+                    // ∂(l·r)/∂r = lᵀ · g. Higher-order derivatives are only
+                    // defined here for matrix-rank operands; the vector-rank
+                    // outer-product adjoints live in `dot_backward`'s concrete
+                    // path, which the first-order `backward` uses.
+                    let c0 = g.dot(&node.deps[1].t());
+                    let c1 = node.deps[0].t().dot(&g);
+                    Self::accumulate_adjoint(&mut adjoints, &node.deps[0], c0);
+                    Self::accumulate_adjoint(&mut adjoints, &node.deps[1], c1);
+                }
+                VariableType::OpT => {
+                    Self::accumulate_adjoint(&mut adjoints, &node.deps[0], g.t());
+                }
+            }
+        }
+
+        adjoints
+    }
 }
 
 /// Basic arithmetic ops implementations
-impl<'a, T: ArithmeticOps + HasGrad<T> + Debug> Var<T> {
+impl<
+        'a,
+        T: ArithmeticOps
+            + HasGrad<T>
+            + Elementwise
+            + Dot<Output = T>
+            + Transpose
+            + Shape
+            + Reduce
+            + Debug,
+    > Var<T>
+{
     pub fn add(&self, other: &Var<T>) -> Var<T> {
         self.handle_bin_op(other, VariableType::OpAdd, add_backward)
     }
@@ -230,7 +546,186 @@ impl<'a, T: ArithmeticOps + HasGrad<T> + Debug> Var<T> {
     pub fn mul(&self, other: &Var<T>) -> Var<T> {
         self.handle_bin_op(other, VariableType::OpMul, mul_backward)
     }
+
+    pub fn div(&self, other: &Var<T>) -> Var<T> {
+        self.handle_bin_op(other, VariableType::OpDiv, div_backward)
+    }
 }
 
-/// Reduce arithmetic implementations
-impl<T: ArithmeticOps + HasGrad<T> + Debug> Var<T> {}
+/// Elementwise nonlinear ops implementations
+impl<
+        'a,
+        T: ArithmeticOps
+            + HasGrad<T>
+            + Elementwise
+            + Dot<Output = T>
+            + Transpose
+            + Shape
+            + Reduce
+            + Debug,
+    > Var<T>
+{
+    pub fn exp(&self) -> Var<T> {
+        self.handle_unary_op(VariableType::OpExp, exp_backward)
+    }
+
+    pub fn ln(&self) -> Var<T> {
+        self.handle_unary_op(VariableType::OpLn, ln_backward)
+    }
+
+    pub fn sin(&self) -> Var<T> {
+        self.handle_unary_op(VariableType::OpSin, sin_backward)
+    }
+
+    pub fn cos(&self) -> Var<T> {
+        self.handle_unary_op(VariableType::OpCos, cos_backward)
+    }
+
+    pub fn sigmoid(&self) -> Var<T> {
+        self.handle_unary_op(VariableType::OpSigmoid, sigmoid_backward)
+    }
+
+    pub fn tanh(&self) -> Var<T> {
+        self.handle_unary_op(VariableType::OpTanh, tanh_backward)
+    }
+
+    pub fn relu(&self) -> Var<T> {
+        self.handle_unary_op(VariableType::OpRelu, relu_backward)
+    }
+
+    pub fn powf(&self, n: f64) -> Var<T> {
+        self.handle_unary_op(VariableType::OpPow(n), powf_backward)
+    }
+
+    /// Multiply elementwise by a scalar constant `n`.
+    pub fn scale(&self, n: f64) -> Var<T> {
+        self.handle_unary_op(VariableType::OpScale(n), scale_backward)
+    }
+}
+
+/// Operator overloads so graphs can be written with ordinary `+ - * /` and unary
+/// `-` on `&Var` references (e.g. `&a + &b`) in addition to the method API.
+/// The [`autodiff!`](crate::autodiff) macro does not use them; it lowers onto
+/// the `add`/`sub`/`mul`/`div`/`scale` methods directly.
+impl<
+        'a,
+        'b,
+        T: ArithmeticOps
+            + HasGrad<T>
+            + Elementwise
+            + Dot<Output = T>
+            + Transpose
+            + Shape
+            + Reduce
+            + Debug,
+    > std::ops::Add<&'b Var<T>> for &'a Var<T>
+{
+    type Output = Var<T>;
+    fn add(self, other: &'b Var<T>) -> Var<T> {
+        Var::add(self, other)
+    }
+}
+
+impl<
+        'a,
+        'b,
+        T: ArithmeticOps
+            + HasGrad<T>
+            + Elementwise
+            + Dot<Output = T>
+            + Transpose
+            + Shape
+            + Reduce
+            + Debug,
+    > std::ops::Sub<&'b Var<T>> for &'a Var<T>
+{
+    type Output = Var<T>;
+    fn sub(self, other: &'b Var<T>) -> Var<T> {
+        Var::sub(self, other)
+    }
+}
+
+impl<
+        'a,
+        'b,
+        T: ArithmeticOps
+            + HasGrad<T>
+            + Elementwise
+            + Dot<Output = T>
+            + Transpose
+            + Shape
+            + Reduce
+            + Debug,
+    > std::ops::Mul<&'b Var<T>> for &'a Var<T>
+{
+    type Output = Var<T>;
+    fn mul(self, other: &'b Var<T>) -> Var<T> {
+        Var::mul(self, other)
+    }
+}
+
+impl<
+        'a,
+        'b,
+        T: ArithmeticOps
+            + HasGrad<T>
+            + Elementwise
+            + Dot<Output = T>
+            + Transpose
+            + Shape
+            + Reduce
+            + Debug,
+    > std::ops::Div<&'b Var<T>> for &'a Var<T>
+{
+    type Output = Var<T>;
+    fn div(self, other: &'b Var<T>) -> Var<T> {
+        Var::div(self, other)
+    }
+}
+
+impl<
+        'a,
+        T: ArithmeticOps
+            + HasGrad<T>
+            + Elementwise
+            + Dot<Output = T>
+            + Transpose
+            + Shape
+            + Reduce
+            + Debug,
+    > std::ops::Neg for &'a Var<T>
+{
+    type Output = Var<T>;
+    fn neg(self) -> Var<T> {
+        // Negation is `x * -1`, built lazily as a scale node like the other op
+        // builders so it works on any (possibly unevaluated) node, not just a
+        // materialized leaf.
+        self.scale(-1.0)
+    }
+}
+
+/// Linear-algebra op implementations
+impl<
+        'a,
+        T: ArithmeticOps
+            + HasGrad<T>
+            + Elementwise
+            + Dot<Output = T>
+            + Transpose
+            + Shape
+            + Reduce
+            + Debug,
+    > Var<T>
+{
+    /// Matrix/batched dot product. The forward contraction and its backward
+    /// rule handle vector, matrix and stacked batch ranks (see
+    /// [`Dot`](crate::traits::Dot) and `dot_backward`).
+    pub fn dot(&self, other: &Var<T>) -> Var<T> {
+        self.handle_bin_op(other, VariableType::OpDot, dot_backward)
+    }
+
+    /// Transpose the last two axes, leaving any leading batch axes in place.
+    pub fn t(&self) -> Var<T> {
+        self.handle_unary_op(VariableType::OpT, transpose_backward)
+    }
+}
@@ -0,0 +1,24 @@
+use rust_autograd::macros::autodiff;
+use rust_autograd::variable::Var;
+
+#[test]
+fn test_autodiff_sigmoid_expression() {
+    let mut x = Var::new(0.0);
+
+    // y = 1/(1+e^-x) = sigmoid(x) written as ordinary math; at x = 0, y = 0.5
+    // and dy/dx = y(1 - y) = 0.25.
+    let (y, dy) = autodiff!(x => 1.0 / (1.0 + (-x).exp()));
+
+    assert!(y.data().unwrap() == 0.5);
+    assert!(dy.unwrap() == 0.25);
+}
+
+#[test]
+fn test_autodiff_product() {
+    let mut x = Var::new(3.0);
+
+    // y = x * x, so dy/dx = 2x = 6 at x = 3.
+    let (_, dy) = autodiff!(x => x * x);
+
+    assert!(dy.unwrap() == 6.0);
+}
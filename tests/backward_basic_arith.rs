@@ -52,4 +52,74 @@ mod test_var_api_v2 {
         z.backward();
         assert!(x.grad().unwrap() == 12.0); // dz/dx == 12?
     }
+
+    #[test]
+    fn test_backward_accumulates_shared_op_node() {
+        let mut x = Var::new(3.0);
+        x.set_requires_grad(true);
+
+        // z = x*x is consumed twice by a = z + z, so a = 2x^2 and da/dx = 4x =
+        // 12 at x = 3. The shared op node z must be visited once with both
+        // consumers' gradients accumulated, not walked once per consumer.
+        let z = x.mul(&x);
+        let mut a = z.add(&z);
+        a.backward();
+
+        assert!(x.grad().unwrap() == 12.0);
+    }
+
+    #[test]
+    fn test_sigmoid_backward() {
+        let mut x = Var::new(0.0);
+        x.set_requires_grad(true);
+
+        // s = sigmoid(x); at x = 0, s = 0.5 and ds/dx = s(1 - s) = 0.25
+        let mut s = x.sigmoid();
+        s.backward();
+
+        assert!(s.data().unwrap() == 0.5);
+        assert!(x.grad().unwrap() == 0.25);
+    }
+
+    #[test]
+    fn test_sin_backward() {
+        let mut x = Var::new(0.0);
+        x.set_requires_grad(true);
+
+        // y = sin(x); at x = 0, y = 0 and dy/dx = cos(0) = 1
+        let mut y = x.sin();
+        y.backward();
+
+        assert!(y.data().unwrap() == 0.0);
+        assert!(x.grad().unwrap() == 1.0);
+    }
+
+    #[test]
+    fn test_powf_backward() {
+        let mut x = Var::new(3.0);
+        x.set_requires_grad(true);
+
+        // z = x^2, so dz/dx = 2x = 6 at x = 3
+        let mut z = x.powf(2.0);
+        z.backward();
+        assert!(x.grad().unwrap() == 6.0);
+    }
+
+    #[test]
+    fn test_second_derivative_cube() {
+        let mut x = Var::new(2.0);
+        x.set_requires_grad(true);
+
+        // y = x^3; dy/dx = 3x^2 = 12 and d2y/dx2 = 6x = 12 at x = 2
+        let mut y = x.powf(3.0);
+        let mut first = y.grad_graph().remove(&x.id()).unwrap();
+        first.eval();
+        assert!(first.data().unwrap() == 12.0);
+
+        // The first derivative is itself a graph, so differentiating it again
+        // yields the second derivative.
+        let mut second = first.grad_graph().remove(&x.id()).unwrap();
+        second.eval();
+        assert!(second.data().unwrap() == 12.0);
+    }
 }
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod test_var_api_v2 {
 
-    use ndarray::array;
+    use ndarray::{arr0, array};
     use rust_autograd::variable::from_ndarray;
 
     #[test]
@@ -53,4 +53,63 @@ mod test_var_api_v2 {
         z.backward();
         assert!(x.grad().unwrap().0 == &array![12.].into_dyn()); // dz/dx == 3?
     }
+
+    #[test]
+    fn test_mul_broadcast_backward() {
+        // z = x * v, where the 1-D vector v broadcasts across x's rows.
+        let mut x = from_ndarray(array![[1., 2.], [3., 4.], [5., 6.]]);
+        let mut v = from_ndarray(array![10., 20.]);
+        x.set_requires_grad(true);
+        v.set_requires_grad(true);
+
+        let mut z = x.mul(&v);
+        z.backward();
+
+        // dz/dx = v broadcast back to x's shape.
+        let x_grad = x.grad().unwrap().0;
+        assert_eq!(x_grad.shape(), &[3, 2]);
+        assert!(x_grad.eq(&array![[10., 20.], [10., 20.], [10., 20.]].into_dyn()));
+
+        // dz/dv = x summed over the broadcast (row) axis, kept at v's shape.
+        let v_grad = v.grad().unwrap().0;
+        assert_eq!(v_grad.shape(), &[2]);
+        assert!(v_grad.eq(&array![9., 12.].into_dyn()));
+    }
+
+    #[test]
+    fn test_mul_broadcast_backward_scalar() {
+        // z = s * x, where the 0-D scalar s broadcasts across every element.
+        let mut s = from_ndarray(arr0(2.0));
+        let mut x = from_ndarray(array![[1., 2.], [3., 4.]]);
+        s.set_requires_grad(true);
+        x.set_requires_grad(true);
+
+        let mut z = s.mul(&x);
+        z.backward();
+
+        // dz/dx = s broadcast back to x's shape.
+        let x_grad = x.grad().unwrap().0;
+        assert_eq!(x_grad.shape(), &[2, 2]);
+        assert!(x_grad.eq(&array![[2., 2.], [2., 2.]].into_dyn()));
+
+        // dz/ds = sum of every element of x, reduced to the scalar's 0-D shape.
+        let s_grad = s.grad().unwrap().0;
+        assert!(s_grad.shape().is_empty());
+        assert!(s_grad.eq(&arr0(10.0).into_dyn()));
+    }
+
+    #[test]
+    fn test_div_backward() {
+        let mut a = from_ndarray(array![2., 4.]);
+        let mut b = from_ndarray(array![1., 2.]);
+        a.set_requires_grad(true);
+        b.set_requires_grad(true);
+
+        let mut z = a.div(&b);
+        z.backward();
+
+        // dz/da = 1/b, dz/db = -a/b^2
+        assert!(a.grad().unwrap().0.eq(&array![1., 0.5].into_dyn()));
+        assert!(b.grad().unwrap().0.eq(&array![-2., -1.].into_dyn()));
+    }
 }
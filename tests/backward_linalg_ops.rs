@@ -30,4 +30,56 @@ mod test_var_api_v2 {
         assert!(x.grad().unwrap().0.eq(&expected_x_grad.into_dyn()));
         assert!(y.grad().unwrap().0.eq(&expected_y_grad.into_dyn()));
     }
+
+    #[test]
+    fn test_dot_backward_vector_matrix() {
+        // a[2] · B[2, 3] -> y[3]; with g = 1 the grads are B·g and a ⊗ g.
+        let mut a = from_ndarray(array![1., 2.]);
+        let mut b = from_ndarray(array![[1., 2., 3.], [4., 5., 6.]]);
+        a.set_requires_grad(true);
+        b.set_requires_grad(true);
+        let mut z = a.dot(&b);
+        z.backward();
+
+        let expected_a_grad = array![6., 15.];
+        let expected_b_grad = array![[1., 1., 1.], [2., 2., 2.]];
+        assert!(a.grad().unwrap().0.eq(&expected_a_grad.into_dyn()));
+        assert!(b.grad().unwrap().0.eq(&expected_b_grad.into_dyn()));
+    }
+
+    #[test]
+    fn test_dot_backward_matrix_vector() {
+        // A[2, 2] · b[2] -> y[2]; with g = 1 the grads are g ⊗ b and Aᵀ·g.
+        let mut a = from_ndarray(array![[1., 2.], [3., 4.]]);
+        let mut b = from_ndarray(array![1., 1.]);
+        a.set_requires_grad(true);
+        b.set_requires_grad(true);
+        let mut z = a.dot(&b);
+        z.backward();
+
+        let expected_a_grad = array![[1., 1.], [1., 1.]];
+        let expected_b_grad = array![4., 6.];
+        assert!(a.grad().unwrap().0.eq(&expected_a_grad.into_dyn()));
+        assert!(b.grad().unwrap().0.eq(&expected_b_grad.into_dyn()));
+    }
+
+    #[test]
+    fn test_dot_backward_batched() {
+        // Two stacked 2x2 matmuls against identity slices: y = A, and with g = 1
+        // grad_A = 1 · Iᵀ = 1 per slice while grad_B = Aᵀ · 1 per slice.
+        let mut a = from_ndarray(array![[[1., 2.], [3., 4.]], [[5., 6.], [7., 8.]]]);
+        let mut b = from_ndarray(array![[[1., 0.], [0., 1.]], [[1., 0.], [0., 1.]]]);
+        a.set_requires_grad(true);
+        b.set_requires_grad(true);
+        let mut z = a.dot(&b);
+        z.eval();
+        assert!(z.data().unwrap().0.eq(&array![[[1., 2.], [3., 4.]], [[5., 6.], [7., 8.]]].into_dyn()));
+
+        z.backward();
+
+        let expected_a_grad = array![[[1., 1.], [1., 1.]], [[1., 1.], [1., 1.]]];
+        let expected_b_grad = array![[[4., 4.], [6., 6.]], [[12., 12.], [14., 14.]]];
+        assert!(a.grad().unwrap().0.eq(&expected_a_grad.into_dyn()));
+        assert!(b.grad().unwrap().0.eq(&expected_b_grad.into_dyn()));
+    }
 }
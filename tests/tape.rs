@@ -0,0 +1,21 @@
+#[cfg(test)]
+mod test_tape {
+
+    use rust_autograd::tape::Tape;
+
+    #[test]
+    fn test_diamond_accumulation() {
+        // z = x + x and a = z + z share the node z, so a = 4x and da/dx = 4.
+        // Sweeping the tape once must accumulate both edges into z and then
+        // both of z's edges into x, rather than visiting x once per path.
+        let mut tape = Tape::new();
+        let x = tape.leaf(3.0);
+        let z = tape.add(x, x);
+        let a = tape.add(z, z);
+
+        tape.backward(a);
+
+        assert!(tape.value(a) == 12.0);
+        assert!(tape.grad(x) == 4.0);
+    }
+}